@@ -97,8 +97,10 @@ impl App {
             let errmsg = || {
                 anyhow::anyhow!(r#"Program "{}" not found"#, &self.program)
             };
-            let hits = coruma::lookup_executable_in_path(&self.program);
-            AbsolutePath::resolve(hits.first().ok_or_else(errmsg)?)?
+            let hit =
+                coruma::lookup_first_executable_in_path(&self.program)
+                    .ok_or_else(errmsg)?;
+            AbsolutePath::resolve(&hit)?
         };
 
         debug!(?starter);
@@ -1,34 +1,122 @@
 use tracing::debug;
 use tracing::trace;
 
+use std::collections::HashSet;
+use std::ffi::OsStr;
 use std::path::PathBuf;
 
 /// Walk through all directories in $PATH, search for
 /// the executable of `name` in each one. Returns a list
-/// of paths that have it.
+/// of paths that have it, which is empty if $PATH is unset.
 #[tracing::instrument]
-#[allow(clippy::missing_panics_doc)]
 pub fn lookup_executable_in_path(program: &str) -> Vec<PathBuf> {
     debug!("Try find executable in $PATH");
 
-    let env_path = std::env::var_os("PATH").expect("Can't read $PATH!?");
+    let env_path = std::env::var_os("PATH");
 
     debug!(?env_path);
 
-    let mut accu = Vec::with_capacity(10);
+    executables_in(env_path.as_deref(), program).collect()
+}
+
+/// Like [`lookup_executable_in_path`] but stops at the
+/// first executable found. Returns `None` if $PATH is unset.
+#[tracing::instrument]
+pub fn lookup_first_executable_in_path(program: &str) -> Option<PathBuf> {
+    debug!("Try find the first executable in $PATH");
+
+    let env_path = std::env::var_os("PATH");
 
-    for dir in std::env::split_paths(&env_path) {
+    debug!(?env_path);
+
+    let mut hits = executables_in(env_path.as_deref(), program);
+    hits.next()
+}
+
+/// Lazily yield `program` in each directory of `env_path`
+/// if it's executable there. Repeated directories are only
+/// looked into once. Yields nothing if `env_path` is `None`.
+fn executables_in<'a>(
+    env_path: Option<&'a OsStr>,
+    program: &'a str,
+) -> impl Iterator<Item = PathBuf> + 'a {
+    let mut seen = HashSet::new();
+    let dirs = env_path.into_iter().flat_map(std::env::split_paths);
+    dirs.filter_map(move |dir| {
         use ino_path::IsExecutable;
 
+        if !seen.insert(dir.clone()) {
+            trace!(?dir, "Skip repeated directory");
+            return None;
+        }
+
         trace!(?dir, "Look into directory");
         let full_path = dir.join(program);
         trace!(?full_path);
 
-        if full_path.is_executable() {
+        full_path.is_executable().then(|| {
             debug!(?full_path, "Found executable");
-            accu.push(full_path);
-        }
+            full_path
+        })
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::*;
+
+    use std::fs::Permissions;
+    use std::os::unix::fs::PermissionsExt;
+
+    use assert_fs::TempDir;
+    use assert_fs::prelude::*;
+
+    fn make_executable(dir: &TempDir, name: &str) -> PathBuf {
+        let file = dir.child(name);
+        file.touch().unwrap();
+        std::fs::set_permissions(&file, Permissions::from_mode(0o755))
+            .unwrap();
+        file.to_path_buf()
+    }
+
+    #[test]
+    fn first_executable() {
+        let first = TempDir::new().unwrap();
+        let second = TempDir::new().unwrap();
+        let first_hit = make_executable(&first, "foo");
+        let second_hit = make_executable(&second, "foo");
+
+        let env_path =
+            std::env::join_paths([first.path(), second.path()]).unwrap();
+
+        assert_eq!(
+            vec![first_hit.clone(), second_hit],
+            executables_in(Some(&env_path), "foo").collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(first_hit),
+            executables_in(Some(&env_path), "foo").next()
+        );
+        assert_eq!(None, executables_in(Some(&env_path), "bar").next());
+    }
+
+    #[test]
+    fn repeated_dirs() {
+        let dir = TempDir::new().unwrap();
+        let hit = make_executable(&dir, "foo");
+
+        let env_path =
+            std::env::join_paths([dir.path(), dir.path()]).unwrap();
+
+        assert_eq!(
+            vec![hit],
+            executables_in(Some(&env_path), "foo").collect::<Vec<_>>()
+        );
     }
 
-    accu
+    #[test]
+    fn path_unset() {
+        assert_eq!(None, executables_in(None, "sh").next());
+    }
 }
@@ -3,9 +3,12 @@
 //! # Basic Usage
 //!
 //! ```rust
+//! use ino_color::ceprintln;
 //! use ino_color::cprintln;
 //! use ino_color::fg;
 //! use ino_color::bg;
+//! use ino_color::palette;
+//! use ino_color::rgb;
 //! use ino_color::style;
 //!
 //! // Foreground color only
@@ -14,15 +17,34 @@
 //! // Foreground + style
 //! cprintln!((fg::Blue, style::Italic), "Savoy blue");
 //!
+//! // Foreground + background
+//! cprintln!((fg::White, bg::Blue), "White on blue");
+//!
 //! // Foreground + background + style
 //! cprintln!((fg::Green, bg::Black, style::Bold),
 //!     "Green on black, bold");
 //!
+//! // 256 colors palette
+//! cprintln!(palette::Indexed<208>, "Also orange");
+//!
+//! // True color
+//! cprintln!(rgb::Rgb<255, 128, 0>, "Orange");
+//!
+//! // Color chosen at runtime
+//! let accent = ino_color::DynColor::Rgb(255, 128, 0);
+//! cprintln!(dyn accent, "Orange from config");
+//!
 //! // All format traits work as expected
 //! cprintln!(fg::Green, "{:?}", vec![123]);
 //! cprintln!(fg::Green, "{:X}", 123);
+//!
+//! // So do captured identifiers, here printing to stderr
+//! let e = "disk is full";
+//! ceprintln!(fg::Red, "failed: {e}");
 //! ```
 
+use std::ffi::OsStr;
+use std::ffi::OsString;
 use std::io::IsTerminal;
 use std::sync::LazyLock;
 
@@ -49,50 +71,83 @@ struct EnvSet {
     clicolor: Option<bool>,
 }
 
-static ENV_SET: LazyLock<EnvSet> = LazyLock::new(|| {
-    EnvSet {
-        // NO_COLOR is presence-based per its own spec.
-        no_color: std::env::var_os("NO_COLOR").is_some(),
-        // FORCE_COLOR: present and non-empty → force color.
-        // Per <https://force-color.org/>.
-        force_color: std::env::var_os("FORCE_COLOR")
-            .is_some_and(|v| !v.is_empty()),
-        clicolor_force: std::env::var("CLICOLOR_FORCE")
-            .is_ok_and(|v| v != "0"),
-        clicolor: match std::env::var("CLICOLOR").as_deref() {
-            Ok("1") => Some(true),
-            Ok("0") => Some(false),
-            _ => None,
-        },
-    }
-});
+impl EnvSet {
+    /// Collect the variables through `var`, which is
+    /// [`std::env::var_os`] outside of tests.
+    fn from_env(var: impl Fn(&str) -> Option<OsString>) -> Self {
+        Self {
+            // NO_COLOR: present and non-empty → no color.
+            // Per <https://no-color.org/>.
+            no_color: var("NO_COLOR").is_some_and(|v| !v.is_empty()),
+            // FORCE_COLOR: present and non-empty → force color.
+            // Per <https://force-color.org/>.
+            force_color: var("FORCE_COLOR").is_some_and(|v| !v.is_empty()),
+            clicolor_force: var("CLICOLOR_FORCE")
+                .is_some_and(|v| v != "0"),
+            clicolor: match var("CLICOLOR")
+                .as_deref()
+                .and_then(OsStr::to_str)
+            {
+                Some("1") => Some(true),
+                Some("0") => Some(false),
+                _ => None,
+            },
+        }
+    }
 
-impl<T: IsTerminal> HasColors for T {
-    #[inline]
-    fn has_colors(&self) -> bool {
+    fn has_colors(&self, is_terminal: bool) -> bool {
         // Priority: FORCE_COLOR > NO_COLOR > CLICOLOR_FORCE
         // > CLICOLOR > default (tty).
         // FORCE_COLOR overrides everything per force-color.org.
-        if ENV_SET.force_color {
+        if self.force_color {
             return true;
         }
-        if ENV_SET.no_color {
+        if self.no_color {
             return false;
         }
-        if ENV_SET.clicolor_force {
+        if self.clicolor_force {
             return true;
         }
         // CLICOLOR=0 disables; CLICOLOR=1 or unset → tty.
-        match ENV_SET.clicolor {
+        match self.clicolor {
             Some(false) => false,
-            _ => self.is_terminal(),
+            _ => is_terminal,
         }
     }
 }
 
+static ENV_SET: LazyLock<EnvSet> =
+    LazyLock::new(|| EnvSet::from_env(|it| std::env::var_os(it)));
+
+impl<T: IsTerminal> HasColors for T {
+    #[inline]
+    fn has_colors(&self) -> bool {
+        ENV_SET.has_colors(self.is_terminal())
+    }
+}
+
 /// An attribute in the [ANSI SGR](https://w.wiki/DBZ2) list.
 pub trait AnsiSgr {
-    const ATTR: u8;
+    /// Write the parameters of this attribute without the surrounding
+    /// `ESC[` and `m`, e.g. `31` or `38;2;255;128;0`.
+    #[allow(clippy::missing_errors_doc)]
+    fn fmt_attr(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+}
+
+/// [`Display`](std::fmt::Display) the parameters of the [`AnsiSgr`]
+/// attribute `A`, so that they can be fed to [`write!`].
+#[doc(hidden)]
+pub struct Attr<A>(std::marker::PhantomData<A>);
+
+impl<A> Attr<A> {
+    pub const NEW: Self = Self(std::marker::PhantomData);
+}
+
+impl<A: AnsiSgr> std::fmt::Display for Attr<A> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        A::fmt_attr(f)
+    }
 }
 
 /// The corresponding attribute is for *foreground color*.
@@ -110,7 +165,11 @@ macro_rules! lets_colors {
             $(
             pub struct $name;
             impl crate::AnsiSgr for $name {
-                const ATTR: u8 = $fg;
+                fn fmt_attr(
+                    f: &mut std::fmt::Formatter<'_>,
+                ) -> std::fmt::Result {
+                    write!(f, "{}", $fg)
+                }
             }
             impl crate::FG for $name {}
             )*
@@ -120,14 +179,64 @@ macro_rules! lets_colors {
             $(
             pub struct $name;
             impl crate::AnsiSgr for $name {
-                const ATTR: u8 = $bg;
+                fn fmt_attr(
+                    f: &mut std::fmt::Formatter<'_>,
+                ) -> std::fmt::Result {
+                    write!(f, "{}", $bg)
+                }
             }
             impl crate::BG for $name {}
             )*
         }
+
+        /// A color chosen at runtime, e.g. from a config file,
+        /// to be used with the `dyn` form of the print macros.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum DynColor {
+            $( $name, )*
+            /// See [`palette::Indexed`].
+            Indexed(u8),
+            /// See [`rgb::Rgb`].
+            Rgb(u8, u8, u8),
+        }
+
+        impl DynColor {
+            fn fmt_attr(
+                self,
+                f: &mut std::fmt::Formatter<'_>,
+                is_bg: bool,
+            ) -> std::fmt::Result {
+                let ext = if is_bg { 48 } else { 38 };
+                match self {
+                    $( Self::$name => {
+                        write!(f, "{}", if is_bg { $bg } else { $fg })
+                    } )*
+                    Self::Indexed(n) => write!(f, "{ext};5;{n}"),
+                    Self::Rgb(r, g, b) => write!(f, "{ext};2;{r};{g};{b}"),
+                }
+            }
+        }
     };
 }
 
+/// [`Display`](std::fmt::Display) the parameters of
+/// a [`DynColor`] used as foreground or background.
+#[doc(hidden)]
+pub enum DynAttr {
+    Fg(DynColor),
+    Bg(DynColor),
+}
+
+impl std::fmt::Display for DynAttr {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fg(color) => color.fmt_attr(f, false),
+            Self::Bg(color) => color.fmt_attr(f, true),
+        }
+    }
+}
+
 lets_colors! {
     Default       39  49,
     Black         30  40,
@@ -148,6 +257,52 @@ lets_colors! {
     BrightWhite   97 107,
 }
 
+/// True colors (24-bit RGB).
+pub mod rgb {
+    /// Foreground true color, e.g. `Rgb<255, 128, 0>`.
+    pub struct Rgb<const R: u8, const G: u8, const B: u8>;
+    impl<const R: u8, const G: u8, const B: u8> crate::AnsiSgr
+        for Rgb<R, G, B>
+    {
+        fn fmt_attr(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "38;2;{R};{G};{B}")
+        }
+    }
+    impl<const R: u8, const G: u8, const B: u8> crate::FG for Rgb<R, G, B> {}
+
+    /// Background true color, e.g. `BgRgb<255, 128, 0>`.
+    pub struct BgRgb<const R: u8, const G: u8, const B: u8>;
+    impl<const R: u8, const G: u8, const B: u8> crate::AnsiSgr
+        for BgRgb<R, G, B>
+    {
+        fn fmt_attr(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "48;2;{R};{G};{B}")
+        }
+    }
+    impl<const R: u8, const G: u8, const B: u8> crate::BG for BgRgb<R, G, B> {}
+}
+
+/// The 256 colors (8-bit) palette.
+pub mod palette {
+    /// Foreground color from the palette, e.g. `Indexed<208>`.
+    pub struct Indexed<const N: u8>;
+    impl<const N: u8> crate::AnsiSgr for Indexed<N> {
+        fn fmt_attr(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "38;5;{N}")
+        }
+    }
+    impl<const N: u8> crate::FG for Indexed<N> {}
+
+    /// Background color from the palette, e.g. `BgIndexed<208>`.
+    pub struct BgIndexed<const N: u8>;
+    impl<const N: u8> crate::AnsiSgr for BgIndexed<N> {
+        fn fmt_attr(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "48;5;{N}")
+        }
+    }
+    impl<const N: u8> crate::BG for BgIndexed<N> {}
+}
+
 macro_rules! lets_styles {
     ( $( $name:ident $attr:literal ),* $(,)? ) => {
         /// Commonly used style attributes.
@@ -155,7 +310,11 @@ macro_rules! lets_styles {
             $(
             pub struct $name;
             impl crate::AnsiSgr for $name {
-                const ATTR: u8 = $attr;
+                fn fmt_attr(
+                    f: &mut std::fmt::Formatter<'_>,
+                ) -> std::fmt::Result {
+                    write!(f, "{}", $attr)
+                }
             }
             impl crate::Style for $name {}
             )*
@@ -181,62 +340,63 @@ lets_styles! {
     Overline 53,
 }
 
-/// Write the ANSI SGR prefix for 1–3 attributes.
-#[macro_export]
+/// Core color-print logic, writing `args` to `writer` wrapped in
+/// a single ANSI SGR sequence made of `attrs` and a trailing reset,
+/// if `should_color`.
+///
+/// Called by the print macros with the locked stream, whether the
+/// stream [`HasColors`], and the attributes, each of which
+/// [`Display`](std::fmt::Display)s its parameters.
 #[doc(hidden)]
-macro_rules! __ino_sgr {
-    ($lock:ident, $fg:path) => {
-        write!($lock, "\x1b[{}m", <$fg as $crate::AnsiSgr>::ATTR).unwrap()
-    };
-    ($lock:ident, $fg:path, $sty:path) => {
-        write!(
-            $lock,
-            "\x1b[{};{}m",
-            <$fg as $crate::AnsiSgr>::ATTR,
-            <$sty as $crate::AnsiSgr>::ATTR
-        )
-        .unwrap()
-    };
-    ($lock:ident, $fg:path, $bg:path, $sty:path) => {
-        write!(
-            $lock,
-            "\x1b[{};{};{}m",
-            <$fg as $crate::AnsiSgr>::ATTR,
-            <$bg as $crate::AnsiSgr>::ATTR,
-            <$sty as $crate::AnsiSgr>::ATTR
-        )
-        .unwrap()
-    };
+pub fn write_colored(
+    mut writer: impl std::io::Write,
+    should_color: bool,
+    attrs: &[&dyn std::fmt::Display],
+    args: std::fmt::Arguments<'_>,
+    newline: bool,
+) -> std::io::Result<()> {
+    if should_color {
+        write!(writer, "\x1b[")?;
+        for (idx, attr) in attrs.iter().enumerate() {
+            if idx != 0 {
+                write!(writer, ";")?;
+            }
+            write!(writer, "{attr}")?;
+        }
+        write!(writer, "m")?;
+    }
+    writer.write_fmt(args)?;
+    if should_color {
+        write!(writer, "\x1b[0m")?;
+    }
+    if newline {
+        writeln!(writer)?;
+    }
+    Ok(())
 }
 
-/// Core color-print logic.
-///
-/// Called by the print macros with the stream, newline flag,
-/// SGR attributes, and format arguments.
+/// Feed the stream and attributes of the print macros
+/// to [`write_colored`].
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __ino_print {
     (
         $stream:expr,
         $newline:tt,
-        ($($attr:path),+),
+        ($($attr:expr),+),
         $($param:tt)*
     ) => {{
         use $crate::HasColors;
-        use std::io::Write;
         let stream = $stream;
         let should_color = stream.has_colors();
-        let mut lock = stream.lock();
-        if should_color {
-            $crate::__ino_sgr!(lock, $($attr),+);
-        }
-        write!(lock, $($param)*).unwrap();
-        if should_color {
-            write!(lock, "\x1b[0m").unwrap();
-        }
-        if $newline {
-            writeln!(lock).unwrap();
-        }
+        $crate::write_colored(
+            stream.lock(),
+            should_color,
+            &[$(&$attr),+],
+            format_args!($($param)*),
+            $newline,
+        )
+        .unwrap();
     }};
 }
 
@@ -269,7 +429,10 @@ macro_rules! create_print_macro {
             "## Syntax\n",
             "- `", stringify!($name), "!(FG, ..)` — foreground only\n",
             "- `", stringify!($name), "!((FG, STYLE), ..)` — foreground + style\n",
+            "- `", stringify!($name), "!((FG, BG), ..)` — foreground + background\n",
             "- `", stringify!($name), "!((FG, BG, STYLE), ..)`", " — foreground + background + style\n",
+            "- `", stringify!($name), "!(dyn FG, ..)` — runtime foreground, a [`DynColor`]\n",
+            "- `", stringify!($name), "!(dyn (FG, BG), ..)` — runtime foreground + background\n",
             "\n",
             "Color/style is only emitted when the target stream supports it (see [`HasColors`]).\n\n",
             "## Example\n",
@@ -282,12 +445,36 @@ macro_rules! create_print_macro {
             "```\n",
         )]
         macro_rules! $name {
+            (
+                dyn ($dol fg:expr, $dol bg:expr),
+                $dol ($dol param:tt)*
+            ) => {
+                $crate::__ino_print!(
+                    $stream(), $newline,
+                    (
+                        $crate::DynAttr::Fg($dol fg),
+                        $crate::DynAttr::Bg($dol bg)
+                    ),
+                    $dol ($dol param)*
+                )
+            };
+            (
+                dyn $dol fg:expr,
+                $dol ($dol param:tt)*
+            ) => {
+                $crate::__ino_print!(
+                    $stream(), $newline,
+                    ($crate::DynAttr::Fg($dol fg)),
+                    $dol ($dol param)*
+                )
+            };
             (
                 $dol fg:path,
                 $dol ($dol param:tt)*
             ) => {
                 $crate::__ino_print!(
-                    $stream(), $newline, ($dol fg),
+                    $stream(), $newline,
+                    ($crate::Attr::<$dol fg>::NEW),
                     $dol ($dol param)*
                 )
             };
@@ -297,7 +484,10 @@ macro_rules! create_print_macro {
             ) => {
                 $crate::__ino_print!(
                     $stream(), $newline,
-                    ($dol fg, $dol sty),
+                    (
+                        $crate::Attr::<$dol fg>::NEW,
+                        $crate::Attr::<$dol sty>::NEW
+                    ),
                     $dol ($dol param)*
                 )
             };
@@ -307,7 +497,11 @@ macro_rules! create_print_macro {
             ) => {
                 $crate::__ino_print!(
                     $stream(), $newline,
-                    ($dol fg, $dol bg, $dol sty),
+                    (
+                        $crate::Attr::<$dol fg>::NEW,
+                        $crate::Attr::<$dol bg>::NEW,
+                        $crate::Attr::<$dol sty>::NEW
+                    ),
                     $dol ($dol param)*
                 )
             };
@@ -335,12 +529,140 @@ create_print_macro! {
     (ceprintln, std::eprintln, std::io::stderr, true),
 }
 
+/// A hyperlink made of [OSC 8] escape sequences, see [`link`].
+///
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+pub struct Link<'a, T> {
+    url: &'a str,
+    text: T,
+    enabled: bool,
+}
+
+/// Make `text` a hyperlink pointing to `url`, which is then shown
+/// with the print macros or any other formatting machinery.
+///
+/// Like colors, the hyperlink is only emitted when `stream`
+/// supports it (see [`HasColors`]), otherwise only `text` is shown.
+///
+/// ```rust
+/// use ino_color::cprintln;
+/// use ino_color::fg;
+/// use ino_color::link;
+///
+/// let url = "https://github.com/MidAutumnMoon/InOri";
+/// cprintln!(fg::Blue, "See {}", link(&std::io::stdout(), url, "InOri"));
+/// ```
+pub fn link<'a, T: std::fmt::Display>(
+    stream: &impl HasColors,
+    url: &'a str,
+    text: T,
+) -> Link<'a, T> {
+    Link {
+        url,
+        text,
+        enabled: stream.has_colors(),
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Link<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.enabled {
+            write!(
+                f,
+                "\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\",
+                self.url, self.text
+            )
+        } else {
+            write!(f, "{}", self.text)
+        }
+    }
+}
+
+/// Remove all ANSI SGR sequences (`ESC[...m`) from `input`,
+/// e.g. before writing colored output into a log file.
+///
+/// Other escape sequences and text are left intact.
+#[must_use]
+pub fn strip_ansi(input: &str) -> String {
+    // N.B. SGR sequences are pure ASCII, removing them
+    // never breaks UTF-8, so nothing is lossy here.
+    String::from_utf8_lossy(&strip_ansi_bytes(input.as_bytes()))
+        .into_owned()
+}
+
+/// Like [`strip_ansi`] but for bytes.
+#[must_use]
+pub fn strip_ansi_bytes(input: &[u8]) -> Vec<u8> {
+    /// Length of the SGR sequence at the start of `seq`, if any.
+    fn sgr_len(seq: &[u8]) -> Option<usize> {
+        let params = seq.strip_prefix(b"\x1b[")?;
+        let end = params
+            .iter()
+            .position(|it| !(it.is_ascii_digit() || *it == b';'))?;
+        (params.get(end) == Some(&b'm')).then_some(2 + end + 1)
+    }
+
+    let mut out = Vec::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(pos) = rest.iter().position(|it| *it == 0x1b) {
+        let (text, seq) = rest.split_at(pos);
+        out.extend_from_slice(text);
+        let skip = sgr_len(seq).unwrap_or_else(|| {
+            // Not SGR, keep the ESC and move on
+            out.push(0x1b);
+            1
+        });
+        rest = seq.get(skip..).unwrap_or_default();
+    }
+    out.extend_from_slice(rest);
+    out
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use fg::*;
     use style::*;
 
+    fn env_set(vars: &[(&str, &str)]) -> EnvSet {
+        EnvSet::from_env(|name| {
+            vars.iter()
+                .find(|(it, _)| *it == name)
+                .map(|(_, value)| value.into())
+        })
+    }
+
+    #[test]
+    fn env_decides_colors() {
+        // nothing set, follow the tty
+        assert!(env_set(&[]).has_colors(true));
+        assert!(!env_set(&[]).has_colors(false));
+
+        // NO_COLOR suppresses colors even on a tty
+        assert!(!env_set(&[("NO_COLOR", "1")]).has_colors(true));
+        // ...but not when it's empty
+        assert!(env_set(&[("NO_COLOR", "")]).has_colors(true));
+
+        // CLICOLOR_FORCE forces colors even when piped
+        assert!(env_set(&[("CLICOLOR_FORCE", "1")]).has_colors(false));
+        assert!(!env_set(&[("CLICOLOR_FORCE", "0")]).has_colors(false));
+        // ...but NO_COLOR wins over it
+        assert!(
+            !env_set(&[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")])
+                .has_colors(true)
+        );
+
+        // FORCE_COLOR wins over everything
+        assert!(
+            env_set(&[("FORCE_COLOR", "1"), ("NO_COLOR", "1")])
+                .has_colors(false)
+        );
+
+        // CLICOLOR=0 disables colors on a tty
+        assert!(!env_set(&[("CLICOLOR", "0")]).has_colors(true));
+        assert!(env_set(&[("CLICOLOR", "1")]).has_colors(true));
+    }
+
     #[test]
     fn fg_only() {
         cprintln!(Blue, "hello");
@@ -353,6 +675,84 @@ mod test {
         cprintln!((Yellow, Bold), "hello {}", "world");
     }
 
+    #[test]
+    fn fg_and_bg() {
+        cprintln!((Blue, bg::Red), "hello");
+        cprintln!((Yellow, bg::Magenta), "hello {}", "world");
+    }
+
+    fn colored(
+        should_color: bool,
+        attrs: &[&dyn std::fmt::Display],
+        newline: bool,
+    ) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_colored(
+            &mut out,
+            should_color,
+            attrs,
+            format_args!("x"),
+            newline,
+        )
+        .expect("writing to Vec never fails");
+        out
+    }
+
+    #[test]
+    fn single_sgr_sequence() {
+        assert_eq!(
+            colored(true, &[&Attr::<Red>::NEW], false),
+            b"\x1b[31mx\x1b[0m"
+        );
+        assert_eq!(
+            colored(
+                true,
+                &[&Attr::<White>::NEW, &Attr::<bg::Blue>::NEW],
+                false
+            ),
+            b"\x1b[37;44mx\x1b[0m"
+        );
+        assert_eq!(
+            colored(
+                true,
+                &[
+                    &DynAttr::Fg(DynColor::Rgb(1, 2, 3)),
+                    &DynAttr::Bg(DynColor::Indexed(4))
+                ],
+                false
+            ),
+            b"\x1b[38;2;1;2;3;48;5;4mx\x1b[0m"
+        );
+        assert_eq!(
+            colored(
+                false,
+                &[&Attr::<White>::NEW, &Attr::<bg::Blue>::NEW],
+                true
+            ),
+            b"x\n"
+        );
+    }
+
+    #[test]
+    fn single_sgr_sequence_with_style() {
+        assert_eq!(
+            colored(true, &[&Attr::<Red>::NEW, &Attr::<Bold>::NEW], false),
+            b"\x1b[31;1mx\x1b[0m"
+        );
+        assert_eq!(
+            colored(
+                true,
+                &[
+                    &Attr::<White>::NEW,
+                    &Attr::<bg::Blue>::NEW,
+                    &Attr::<Bold>::NEW
+                ],
+                true
+            ),
+            b"\x1b[37;44;1mx\x1b[0m\n"
+        );
+    }
+
     #[test]
     fn fg_bg_style() {
         cprintln!((Blue, bg::Red, Italic), "hello");
@@ -367,6 +767,103 @@ mod test {
         ceprintln!(Cyan, "with newline");
     }
 
+    #[test]
+    fn true_color() {
+        cprintln!(rgb::Rgb<255, 128, 0>, "hello");
+        cprintln!((rgb::Rgb<255, 128, 0>, rgb::BgRgb<0, 0, 0>), "hello");
+        assert_eq!(
+            Attr::<rgb::Rgb<255, 128, 0>>::NEW.to_string(),
+            "38;2;255;128;0"
+        );
+        assert_eq!(
+            Attr::<rgb::BgRgb<0, 1, 2>>::NEW.to_string(),
+            "48;2;0;1;2"
+        );
+        assert_eq!(Attr::<Red>::NEW.to_string(), "31");
+    }
+
+    #[test]
+    fn palette() {
+        cprintln!(palette::Indexed<208>, "hello");
+        cprintln!((palette::Indexed<15>, palette::BgIndexed<0>), "hello");
+        assert_eq!(Attr::<palette::Indexed<0>>::NEW.to_string(), "38;5;0");
+        assert_eq!(
+            Attr::<palette::Indexed<15>>::NEW.to_string(),
+            "38;5;15"
+        );
+        assert_eq!(
+            Attr::<palette::Indexed<255>>::NEW.to_string(),
+            "38;5;255"
+        );
+        assert_eq!(
+            Attr::<palette::BgIndexed<255>>::NEW.to_string(),
+            "48;5;255"
+        );
+    }
+
+    #[test]
+    fn dyn_color() {
+        let accent = DynColor::Magenta;
+        cprintln!(dyn accent, "hello");
+        cprintln!(dyn (DynColor::Indexed(208), DynColor::Black), "hello");
+
+        assert_eq!(DynAttr::Fg(DynColor::Red).to_string(), "31");
+        assert_eq!(DynAttr::Bg(DynColor::BrightWhite).to_string(), "107");
+        assert_eq!(
+            DynAttr::Fg(DynColor::Indexed(208)).to_string(),
+            Attr::<palette::Indexed<208>>::NEW.to_string()
+        );
+        assert_eq!(
+            DynAttr::Bg(DynColor::Rgb(1, 2, 3)).to_string(),
+            Attr::<rgb::BgRgb<1, 2, 3>>::NEW.to_string()
+        );
+    }
+
+    #[test]
+    fn hyperlink() {
+        let url = "https://example.com";
+        let on = Link {
+            url,
+            text: 42,
+            enabled: true,
+        };
+        assert_eq!(
+            on.to_string(),
+            "\x1b]8;;https://example.com\x1b\\42\x1b]8;;\x1b\\"
+        );
+        let off = Link {
+            url,
+            text: 42,
+            enabled: false,
+        };
+        assert_eq!(off.to_string(), "42");
+        cprintln!(Blue, "{}", link(&std::io::stdout(), url, "example"));
+    }
+
+    #[test]
+    fn strip_ansi() {
+        use super::strip_ansi;
+
+        let painted = format!(
+            "\x1b[{};{};{}mhello\x1b[0m \x1b[{}mworld\x1b[0m",
+            Attr::<Red>::NEW,
+            Attr::<bg::Black>::NEW,
+            Attr::<Bold>::NEW,
+            Attr::<palette::Indexed<208>>::NEW,
+        );
+        assert_eq!(strip_ansi(&painted), "hello world");
+        assert_eq!(strip_ansi("\x1b[38;2;255;128;0m橙\x1b[0m"), "橙");
+        assert_eq!(strip_ansi("\x1b[0m"), "");
+        assert_eq!(strip_ansi("plain"), "plain");
+
+        // not SGR, kept as is
+        assert_eq!(strip_ansi("\x1b[2Jx\x1b"), "\x1b[2Jx\x1b");
+        assert_eq!(
+            strip_ansi_bytes(b"\x1b[1ma\x1b[0m\xff"),
+            b"a\xff".to_vec()
+        );
+    }
+
     #[test]
     fn format_traits() {
         cprintln!(Green, "{:?}", vec![123]);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::iter::Filter;
 
 /// Some iterator extensions.
@@ -30,6 +32,99 @@ pub trait InoIter: Iterator {
     {
         self.filter(move |e| !pred(e))
     }
+
+    /// Alias of [`Iterator::find`] with a name matching [`Self::select`].
+    /// Returns the first item for which `pred` returns `true`.
+    #[inline]
+    fn find_first<P>(mut self, pred: P) -> Option<Self::Item>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.find(pred)
+    }
+
+    /// Alias of [`Iterator::find_map`] with a name matching [`Self::select`].
+    /// Returns the first `Some` produced by `f`.
+    #[inline]
+    fn select_first<B, F>(mut self, f: F) -> Option<B>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        self.find_map(f)
+    }
+
+    /// Count the occurrences of each item, consuming the iterator.
+    ///
+    /// See also `tally` method from Ruby: <https://docs.ruby-lang.org/en/3.4/Enumerable.html#method-i-tally>
+    #[inline]
+    fn tally(self) -> HashMap<Self::Item, usize>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+    {
+        self.fold(HashMap::new(), |mut counts, item| {
+            *counts.entry(item).or_default() += 1;
+            counts
+        })
+    }
+
+    /// Split an iterator of [`Result`]s into oks and errs in one pass,
+    /// preserving the order within each bucket.
+    #[inline]
+    fn partition_results<T, E>(self) -> (Vec<T>, Vec<E>)
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+    {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for item in self {
+            match item {
+                Ok(ok) => oks.push(ok),
+                Err(err) => errs.push(err),
+            }
+        }
+        (oks, errs)
+    }
+
+    /// Lazily group items into owned chunks of `n` items.
+    /// The last chunk may be shorter if there aren't enough items.
+    ///
+    /// Like [`slice::chunks`] but for iterators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    #[inline]
+    fn chunked(self, n: usize) -> impl Iterator<Item = Vec<Self::Item>>
+    where
+        Self: Sized,
+    {
+        assert!(n != 0, "chunk size must be non-zero");
+        let mut iter = self;
+        std::iter::from_fn(move || {
+            let chunk = iter.by_ref().take(n).collect::<Vec<_>>();
+            (!chunk.is_empty()).then_some(chunk)
+        })
+    }
+
+    /// Lazily collapse runs of consecutive equal items into one,
+    /// like Unix `uniq`. At most two items are held at a time,
+    /// the one to be yielded and the one peeked after it.
+    #[inline]
+    fn dedup_consecutive(self) -> impl Iterator<Item = Self::Item>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        let mut iter = self.peekable();
+        std::iter::from_fn(move || {
+            let item = iter.next()?;
+            while iter.next_if_eq(&item).is_some() {}
+            Some(item)
+        })
+    }
 }
 
 impl<T> InoIter for T where T: Iterator + ?Sized {}
@@ -57,4 +152,74 @@ mod tests {
             nums.into_iter().reject(|n| n % 2 == 0).collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn test_tally() {
+        let words = vec!["a", "b", "a"];
+        assert_eq!(
+            HashMap::from([("a", 2), ("b", 1)]),
+            words.into_iter().tally()
+        );
+    }
+
+    #[test]
+    fn test_partition_results() {
+        let results: Vec<Result<i32, &str>> =
+            vec![Ok(1), Err("x"), Ok(2), Err("y"), Ok(3)];
+        assert_eq!(
+            (vec![1, 2, 3], vec!["x", "y"]),
+            results.into_iter().partition_results()
+        );
+    }
+
+    #[test]
+    fn test_chunked() {
+        assert_eq!(
+            vec![vec![1, 2], vec![3, 4]],
+            (1..=4).chunked(2).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![vec![1, 2, 3], vec![4, 5]],
+            (1..=5).chunked(3).collect::<Vec<_>>()
+        );
+        assert!(std::iter::empty::<i32>().chunked(3).next().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunked_zero() {
+        let _ = (1..=4).chunked(0);
+    }
+
+    #[test]
+    fn test_find_first() {
+        let nums = vec![1, 2, 3, 4];
+        assert_eq!(
+            Some(2),
+            nums.iter().copied().find_first(|n| n % 2 == 0)
+        );
+        assert_eq!(None, nums.into_iter().find_first(|n| *n > 4));
+    }
+
+    #[test]
+    fn test_select_first() {
+        let words = ["a", "1", "b", "2"];
+        assert_eq!(
+            Some(1),
+            words.iter().select_first(|w| w.parse::<i32>().ok())
+        );
+        assert_eq!(
+            None,
+            words.iter().select_first(|w| w.strip_prefix('z'))
+        );
+    }
+
+    #[test]
+    fn test_dedup_consecutive() {
+        let nums = [1, 1, 2, 2, 2, 3, 1];
+        assert_eq!(
+            vec![1, 2, 3, 1],
+            nums.into_iter().dedup_consecutive().collect::<Vec<_>>()
+        );
+    }
 }
@@ -24,6 +24,30 @@ where
     /// or any other error occurs — following the same convention as
     /// [`Path::exists`] and [`Path::is_dir`].
     fn is_executable(&self) -> bool;
+
+    /// Like [`Self::is_executable`], but also tells why the file
+    /// isn't an executable. Unlike [`Self::is_executable`],
+    /// directories are [`ExecStatus::NotAFile`].
+    ///
+    /// Symlinks are followed, the same as what `execve(2)` does.
+    ///
+    /// # Errors
+    ///
+    /// IO errors other than the file not found.
+    fn executable_status(&self) -> std::io::Result<ExecStatus>;
+}
+
+/// The result of [`IsExecutable::executable_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecStatus {
+    /// It's a file and can be executed by us.
+    Executable,
+    /// It's something other than a file, e.g. a directory.
+    NotAFile,
+    /// It's a file but we lack the permission to execute it.
+    NoExecBit,
+    /// Nothing is there.
+    NotFound,
 }
 
 #[cfg(not(unix))]
@@ -32,19 +56,40 @@ compile_error!("`IsExecutable` is only implemented for unix targets");
 #[cfg(unix)]
 mod unix {
 
+    use super::ExecStatus;
     use super::IsExecutable;
     use std::path::Path;
 
+    use rustix::fs::Access;
+    use rustix::fs::AtFlags;
+    use rustix::fs::CWD;
+    use rustix::fs::accessat;
+    use rustix::io::Errno;
+
     impl IsExecutable for Path {
         #[inline]
         fn is_executable(&self) -> bool {
-            use rustix::fs::Access;
-            use rustix::fs::AtFlags;
-            use rustix::fs::CWD;
-            use rustix::fs::accessat;
-
             accessat(CWD, self, Access::EXEC_OK, AtFlags::empty()).is_ok()
         }
+
+        #[inline]
+        fn executable_status(&self) -> std::io::Result<ExecStatus> {
+            let metadata = match self.metadata() {
+                Ok(it) => it,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    return Ok(ExecStatus::NotFound);
+                }
+                Err(err) => return Err(err),
+            };
+            if !metadata.is_file() {
+                return Ok(ExecStatus::NotAFile);
+            }
+            match accessat(CWD, self, Access::EXEC_OK, AtFlags::empty()) {
+                Ok(()) => Ok(ExecStatus::Executable),
+                Err(Errno::ACCESS) => Ok(ExecStatus::NoExecBit),
+                Err(err) => Err(err.into()),
+            }
+        }
     }
 
     #[cfg(test)]
@@ -65,4 +110,35 @@ mod unix {
         let manifest = env!("CARGO_MANIFEST_PATH").pipe(PathBuf::from);
         assert!(!manifest.is_executable());
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn executable_status() {
+        use assert_fs::TempDir;
+        use assert_fs::prelude::*;
+        use std::fs::Permissions;
+        use std::fs::set_permissions;
+        use std::os::unix::fs::PermissionsExt;
+
+        let top = TempDir::new().unwrap();
+        let file = top.child("file");
+        file.touch().unwrap();
+
+        set_permissions(&file, Permissions::from_mode(0o755)).unwrap();
+        assert!(
+            file.executable_status().unwrap() == ExecStatus::Executable
+        );
+
+        set_permissions(&file, Permissions::from_mode(0o644)).unwrap();
+        assert!(
+            file.executable_status().unwrap() == ExecStatus::NoExecBit
+        );
+
+        assert!(top.executable_status().unwrap() == ExecStatus::NotAFile);
+
+        let missing = top.child("missing");
+        assert!(
+            missing.executable_status().unwrap() == ExecStatus::NotFound
+        );
+    }
 }
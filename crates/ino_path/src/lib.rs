@@ -1,4 +1,5 @@
 mod is_executable;
+pub use is_executable::ExecStatus;
 pub use is_executable::IsExecutable;
 use tap::Pipe;
 
@@ -12,6 +13,12 @@ use std::path::PathBuf;
 pub enum PathExtError {
     #[error(r#"Path "{0}" is not absolute"#)]
     NotAbsolute(PathBuf),
+    #[error(r#"Path "{0}" does not exist"#)]
+    NotExist(PathBuf),
+    #[error(r#"Path "{0}" is not a file"#)]
+    NotFile(PathBuf),
+    #[error(r#"Failed to access path "{path}""#)]
+    Io { path: PathBuf, source: io::Error },
 }
 
 /// Extra functions to work with [`Path`].
@@ -27,6 +34,29 @@ pub trait PathExt {
     /// Like [`Path::is_absolute`], but returns error if
     /// this path is not absolute.
     fn must_absolute(&self) -> Result<&Self, PathExtError>;
+
+    /// Like [`Self::try_exists_no_traverse`], but returns error if
+    /// this path doesn't exist.
+    fn must_exist(&self) -> Result<&Self, PathExtError>;
+
+    /// Returns error if this path isn't a regular file.
+    /// Symlinks are **not** traversed, thus a symlink
+    /// to a file isn't a file.
+    fn must_be_file(&self) -> Result<&Self, PathExtError>;
+
+    /// Like [`Path::canonicalize`], but **does not** traverse the
+    /// final component. Only the parent is canonicalized, so for
+    /// a symlink, even a dangling one, its own path is returned
+    /// instead of its target.
+    fn canonicalize_no_traverse(&self) -> IoResult<PathBuf>;
+
+    /// Express this path relative to `base`, walking up with `..`
+    /// as needed, e.g. `/a/b` relative to `/a/x` is `../b`.
+    ///
+    /// This is purely lexical and never touches the filesystem,
+    /// thus `None` is returned if either path isn't absolute or
+    /// contains `..` which can't be resolved lexically.
+    fn relative_to(&self, base: &Path) -> Option<PathBuf>;
 }
 
 impl PathExt for Path {
@@ -58,6 +88,86 @@ impl PathExt for Path {
             PathExtError::NotAbsolute(self.into()).pipe(Err)
         }
     }
+
+    #[inline]
+    fn must_exist(&self) -> Result<&Self, PathExtError> {
+        match self.try_exists_no_traverse() {
+            Ok(true) => Ok(self),
+            Ok(false) => PathExtError::NotExist(self.into()).pipe(Err),
+            Err(source) => PathExtError::Io {
+                path: self.into(),
+                source,
+            }
+            .pipe(Err),
+        }
+    }
+
+    #[inline]
+    fn must_be_file(&self) -> Result<&Self, PathExtError> {
+        match self.symlink_metadata() {
+            Ok(m) if m.is_file() => Ok(self),
+            Ok(_) => PathExtError::NotFile(self.into()).pipe(Err),
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                PathExtError::NotExist(self.into()).pipe(Err)
+            }
+            Err(source) => PathExtError::Io {
+                path: self.into(),
+                source,
+            }
+            .pipe(Err),
+        }
+    }
+
+    #[inline]
+    fn canonicalize_no_traverse(&self) -> IoResult<PathBuf> {
+        // e.g. "/" or "a/.."
+        let (Some(parent), Some(name)) = (self.parent(), self.file_name())
+        else {
+            return self.canonicalize();
+        };
+        // The parent of a bare "a" is an empty path
+        let parent = if parent.as_os_str().is_empty() {
+            Self::new(".")
+        } else {
+            parent
+        };
+        let path = parent.canonicalize()?.join(name);
+        // Same as std, the path must exist
+        path.symlink_metadata()?;
+        Ok(path)
+    }
+
+    #[inline]
+    fn relative_to(&self, base: &Path) -> Option<PathBuf> {
+        use std::path::Component;
+
+        let is_lexical = |it: &Self| {
+            it.is_absolute()
+                && it.components().all(|it| it != Component::ParentDir)
+        };
+        if !is_lexical(self) || !is_lexical(base) {
+            return None;
+        }
+
+        let mut path = self.components().peekable();
+        let mut base = base.components().peekable();
+        while let (Some(p), Some(b)) = (path.peek(), base.peek())
+            && p == b
+        {
+            path.next();
+            base.next();
+        }
+
+        let rel = base
+            .map(|_| Component::ParentDir)
+            .chain(path)
+            .collect::<PathBuf>();
+        if rel.as_os_str().is_empty() {
+            Some(PathBuf::from("."))
+        } else {
+            Some(rel)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -89,6 +199,101 @@ mod test {
         assert!(p.try_exists_no_traverse().unwrap());
     }
 
+    #[test]
+    fn canonicalize_no_traverse() {
+        let top = make_tempdir!();
+        let real_top = top.path().canonicalize().unwrap();
+        let dir = top.child("dir");
+        dir.create_dir_all().unwrap();
+
+        // normal file, same as std
+        let file = top.child("file");
+        file.touch().unwrap();
+        let via_dir = dir.path().join("..").join("file");
+        assert!(
+            via_dir.canonicalize_no_traverse().unwrap()
+                == real_top.join("file")
+        );
+        assert!(
+            via_dir.canonicalize_no_traverse().unwrap()
+                == via_dir.canonicalize().unwrap()
+        );
+
+        // broken symlink, std fails but we don't
+        let link = dir.child("link");
+        symlink("/sys/bbbbbbbroken", &link).unwrap();
+        assert!(link.path().canonicalize().is_err());
+        assert!(
+            link.path().canonicalize_no_traverse().unwrap()
+                == real_top.join("dir").join("link")
+        );
+
+        // missing
+        let missing = top.child("missing");
+        assert!(
+            missing
+                .path()
+                .canonicalize_no_traverse()
+                .unwrap_err()
+                .kind()
+                == ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn relative_to() {
+        let rel = |path: &str, base: &str| {
+            Path::new(path).relative_to(Path::new(base))
+        };
+        assert!(rel("/a/b/c", "/a") == Some("b/c".into()));
+        assert!(rel("/a/b", "/a/x") == Some("../b".into()));
+        assert!(rel("/a", "/a/b/c") == Some("../..".into()));
+        assert!(rel("/a/b", "/x/y") == Some("../../a/b".into()));
+        assert!(rel("/a/b", "/a/b/") == Some(".".into()));
+        assert!(rel("/", "/") == Some(".".into()));
+        // not absolute
+        assert!(rel("a/b", "/a").is_none());
+        assert!(rel("/a/b", "a").is_none());
+        // unresolvable
+        assert!(rel("/a/../b", "/a").is_none());
+    }
+
+    #[test]
+    fn must_exist_and_be_file() {
+        let top = make_tempdir!();
+
+        let missing = top.child("missing");
+        assert!(matches!(
+            missing.path().must_exist(),
+            Err(PathExtError::NotExist(it)) if it == missing.path()
+        ));
+        assert!(matches!(
+            missing.path().must_be_file(),
+            Err(PathExtError::NotExist(_))
+        ));
+
+        let dir = top.child("dir");
+        dir.create_dir_all().unwrap();
+        assert!(dir.path().must_exist().is_ok());
+        assert!(matches!(
+            dir.path().must_be_file(),
+            Err(PathExtError::NotFile(it)) if it == dir.path()
+        ));
+
+        let file = top.child("file");
+        file.touch().unwrap();
+        assert!(file.path().must_exist().unwrap() == file.path());
+        assert!(file.path().must_be_file().unwrap() == file.path());
+
+        let link = top.child("link");
+        symlink(file.path(), &link).unwrap();
+        assert!(link.path().must_exist().is_ok());
+        assert!(matches!(
+            link.path().must_be_file(),
+            Err(PathExtError::NotFile(_))
+        ));
+    }
+
     #[test]
     fn is_dir_no_traverse() {
         let top = make_tempdir!();
@@ -13,6 +13,99 @@ pub trait TapExt: Tap {
     {
         self.tap(|it| tracing::trace!(?it))
     }
+
+    /// Trace self using [`tracing::debug`]
+    ///
+    /// ```
+    /// use ino_tap::TapExt;
+    ///
+    /// let value = vec![1, 2, 3].tap_debug();
+    /// assert_eq!(value, [1, 2, 3]);
+    /// ```
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
+    #[must_use]
+    fn tap_debug(self) -> Self
+    where
+        Self: std::fmt::Debug,
+    {
+        self.tap(|it| tracing::debug!(?it))
+    }
+
+    /// Trace self using [`tracing::info`]
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
+    #[must_use]
+    fn tap_info(self) -> Self
+    where
+        Self: std::fmt::Debug,
+    {
+        self.tap(|it| tracing::info!(?it))
+    }
+
+    /// Trace self using [`tracing::debug`], but formatted
+    /// with [`Display`](std::fmt::Display) instead of [`Debug`]
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
+    #[must_use]
+    fn tap_display(self) -> Self
+    where
+        Self: std::fmt::Display,
+    {
+        self.tap(|it| tracing::debug!(%it))
+    }
+
+    /// Mutate self with `f` then trace the result using
+    /// [`tracing::trace`]
+    ///
+    /// ```
+    /// use ino_tap::TapExt;
+    ///
+    /// let value = vec![3, 1, 2].tap_mut_trace(|it| it.sort());
+    /// assert_eq!(value, [1, 2, 3]);
+    /// ```
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
+    #[must_use]
+    fn tap_mut_trace<F>(self, f: F) -> Self
+    where
+        Self: std::fmt::Debug,
+        F: FnOnce(&mut Self),
+    {
+        self.tap_mut(f).tap_trace()
+    }
 }
 
 impl<T> TapExt for T where T: Sized {}
+
+/// Extension trait to [`Result`] for tracing only the errors.
+pub trait ResultTapExt {
+    /// Trace the error using [`tracing::trace`], leaving
+    /// [`Ok`] untouched.
+    #[must_use]
+    fn tap_err_trace(self) -> Self;
+}
+
+impl<T, E> ResultTapExt for Result<T, E>
+where
+    E: std::fmt::Debug,
+{
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
+    fn tap_err_trace(self) -> Self {
+        self.inspect_err(|err| tracing::trace!(?err))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tap_err_trace() {
+        let ok: Result<i32, &str> = Ok(1);
+        assert_eq!(Ok(1), ok.tap_err_trace());
+        let err: Result<i32, &str> = Err("oops");
+        assert_eq!(Err("oops"), err.tap_err_trace());
+    }
+}
@@ -1,23 +1,209 @@
+use std::fs::File;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tracing_subscriber::filter::Builder as EnvFilterBuilder;
+use tracing_subscriber::filter::EnvFilter;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+
+pub use tracing_subscriber::filter::LevelFilter;
+
+/// Where the logs go.
+#[derive(Debug, Clone, Default)]
+pub enum Output {
+    #[default]
+    Stderr,
+    Stdout,
+    /// Append to the file, creating it if missing.
+    File(PathBuf),
+}
+
+/// How each log line looks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// The default format of [`tracing_subscriber::fmt`].
+    #[default]
+    Full,
+    /// Multi-line and human friendly.
+    Pretty,
+    /// Shorter single line.
+    Compact,
+    /// Newline-delimited JSON, for log collectors.
+    Json,
+}
+
+impl Output {
+    /// Whether the output is a terminal. Files never are.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            Self::Stderr => std::io::stderr().is_terminal(),
+            Self::Stdout => std::io::stdout().is_terminal(),
+            Self::File(_) => false,
+        }
+    }
+}
+
+/// Options for [`init_tracing_subscriber_with`].
+#[derive(Debug, Clone)]
+pub struct TracingOpts {
+    pub output: Output,
+    /// Whether to emit ANSI escape codes. If `None`, they are
+    /// emitted only when [`Self::output`] is a terminal.
+    pub ansi: Option<bool>,
+    pub format: LogFormat,
+    /// The level used when `RUST_LOG` is not set.
+    pub level: LevelFilter,
+}
+
+impl Default for TracingOpts {
+    fn default() -> Self {
+        Self {
+            output: Output::default(),
+            ansi: None,
+            format: LogFormat::default(),
+            level: LevelFilter::INFO,
+        }
+    }
+}
+
+impl TracingOpts {
+    fn ansi(&self) -> bool {
+        self.ansi.unwrap_or_else(|| self.output.is_terminal())
+    }
+}
+
 /// Init custom [`tracing_subscriber`] configuration.
-#[inline(always)]
-#[allow(clippy::wildcard_imports)]
+#[inline]
 pub fn init_tracing_subscriber() {
-    use tracing_subscriber::filter::*;
+    init_tracing_subscriber_at(LevelFilter::INFO);
+}
+
+/// Like [`init_tracing_subscriber`] but logs at `level` by default.
+/// `RUST_LOG` still takes precedence.
+#[inline]
+pub fn init_tracing_subscriber_at(level: LevelFilter) {
+    // Writing to stderr never fails to set up.
+    let _ = init_tracing_subscriber_with(TracingOpts {
+        level,
+        ..Default::default()
+    });
+}
+
+/// Like [`init_tracing_subscriber`] but with custom [`TracingOpts`].
+///
+/// # Errors
+///
+/// Returns error if [`Output::File`] can't be opened.
+pub fn init_tracing_subscriber_with(
+    opts: TracingOpts,
+) -> std::io::Result<()> {
+    use tracing_subscriber::prelude::*;
+
+    build_subscriber(opts)?.init();
+    Ok(())
+}
+
+fn env_filter_builder(level: LevelFilter) -> EnvFilterBuilder {
+    EnvFilter::builder().with_default_directive(level.into())
+}
+
+fn build_subscriber(
+    opts: TracingOpts,
+) -> std::io::Result<impl tracing::Subscriber + Send + Sync + 'static> {
     use tracing_subscriber::prelude::*;
 
     use tracing_subscriber::{fmt, registry};
 
-    use std::io::IsTerminal;
+    let ansi = opts.ansi();
+
+    let writer = match opts.output {
+        Output::Stderr => BoxMakeWriter::new(std::io::stderr),
+        Output::Stdout => BoxMakeWriter::new(std::io::stdout),
+        Output::File(path) => File::options()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map(Mutex::new)
+            .map(BoxMakeWriter::new)?,
+    };
+
+    let fmt_layer = fmt::layer().with_writer(writer).with_ansi(ansi);
+    let fmt_layer = match opts.format {
+        LogFormat::Full => fmt_layer.boxed(),
+        LogFormat::Pretty => fmt_layer.pretty().boxed(),
+        LogFormat::Compact => fmt_layer.compact().boxed(),
+        LogFormat::Json => fmt_layer.json().boxed(),
+    };
+
+    let env_layer = env_filter_builder(opts.level).from_env_lossy();
+
+    Ok(registry().with(fmt_layer).with(env_layer))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn build_with_opts() {
+        let tmp = assert_fs::TempDir::new().unwrap();
+        for output in [
+            Output::Stderr,
+            Output::Stdout,
+            Output::File(tmp.path().join("log")),
+        ] {
+            let opts = TracingOpts {
+                output,
+                ansi: Some(false),
+                ..Default::default()
+            };
+            assert!(build_subscriber(opts).is_ok());
+        }
+        assert!(tmp.path().join("log").is_file());
+    }
+
+    #[test]
+    fn ansi_auto_detect() {
+        let opts = TracingOpts {
+            output: Output::File("log".into()),
+            ..Default::default()
+        };
+        assert!(!opts.ansi());
 
-    let output = std::io::stderr;
+        let opts = TracingOpts {
+            ansi: Some(true),
+            ..opts
+        };
+        assert!(opts.ansi());
+    }
 
-    let fmt_layer = fmt::layer()
-        .with_writer(output)
-        .with_ansi(output().is_terminal());
+    #[test]
+    fn default_level() {
+        let filter =
+            env_filter_builder(LevelFilter::DEBUG).parse_lossy("");
+        assert_eq!(Some(LevelFilter::DEBUG), filter.max_level_hint());
 
-    let env_layer = EnvFilter::builder()
-        .with_default_directive(LevelFilter::INFO.into())
-        .from_env_lossy();
+        let filter =
+            env_filter_builder(LevelFilter::DEBUG).parse_lossy("warn");
+        assert_eq!(Some(LevelFilter::WARN), filter.max_level_hint());
+    }
 
-    registry().with(fmt_layer).with(env_layer).init();
+    #[test]
+    fn build_with_format() {
+        for format in [
+            LogFormat::Full,
+            LogFormat::Pretty,
+            LogFormat::Compact,
+            LogFormat::Json,
+        ] {
+            let opts = TracingOpts {
+                format,
+                ..Default::default()
+            };
+            assert!(build_subscriber(opts).is_ok());
+        }
+    }
 }
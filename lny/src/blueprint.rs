@@ -135,6 +135,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn reject_unsupported_version() {
+        let json = serde_json::json! { {
+            "version": CURRENT_BLUEPRINT_VERSION + 1,
+            "symlinks": []
+        } };
+        let der = json.into_deserializer();
+        let res = Blueprint::deserialize(der);
+        assert!(
+            res.expect_err("it should error")
+                .to_string()
+                .contains("version mismatch")
+        );
+    }
+
     #[test]
     fn be_strict_when_parsing() {
         let json = serde_json::json!( {
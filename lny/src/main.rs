@@ -3,10 +3,15 @@ mod step;
 mod template;
 
 use crate::blueprint::Blueprint;
+use crate::step::Step;
 use crate::step::StepQueue;
 
 use anyhow::Context;
 use anyhow::Result as AnyResult;
+use anyhow::bail;
+use ino_color::ceprintln;
+use ino_color::fg;
+use itertools::Itertools;
 use tracing::debug;
 use tracing::info;
 use tracing::warn;
@@ -15,8 +20,6 @@ use ino_tap::TapExt;
 
 use std::path::PathBuf;
 
-// TODO: use thiserror to replace ad-hoc string errors
-
 /// Maintaining symlinks.
 #[derive(clap::Parser, Debug)]
 struct CliOpts {
@@ -27,6 +30,18 @@ struct CliOpts {
     /// will be removed.
     #[arg(long, short, value_name = "PATH")]
     old_blueprint: Option<PathBuf>,
+    /// Check the blueprints and print the planned steps
+    /// without touching the filesystem.
+    #[arg(long)]
+    dry_run: bool,
+    /// Fail instead of warn if the src of a symlink
+    /// to be created doesn't exist.
+    #[arg(long)]
+    require_src: bool,
+    /// Check feasibility of steps in parallel,
+    /// helps with large blueprints.
+    #[arg(long)]
+    parallel_check: bool,
 }
 
 impl CliOpts {
@@ -68,9 +83,28 @@ fn run(cliopts: CliOpts) -> AnyResult<()> {
     info!("Check feasibility");
 
     // TODO: use new type for checked steps?
-    // TODO: structural error for reporting
-    for step in step_queue.clone() {
-        step.check_feasibility()?;
+    let errors = step_queue
+        .check_feasibility(cliopts.require_src, cliopts.parallel_check)
+        .into_iter()
+        .map(anyhow::Error::from)
+        .collect_vec();
+
+    if !errors.is_empty() {
+        for err in &errors {
+            ceprintln!(fg::Red, "{err:#}");
+        }
+        bail!(
+            "{} step(s) are not feasible, nothing has been changed",
+            errors.len()
+        );
+    }
+
+    if cliopts.dry_run {
+        info!("Dry run, print planned steps");
+        step_queue
+            .filter(|it| !matches!(it, Step::Nothing))
+            .for_each(|it| println!("{it}"));
+        return Ok(());
     }
 
     info!("Execute blueprint");
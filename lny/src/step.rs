@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fs::remove_file;
 use std::fs::rename;
@@ -8,9 +9,7 @@ use std::path::PathBuf;
 use crate::blueprint::Blueprint;
 use crate::blueprint::Symlink;
 
-use anyhow::Context;
 use anyhow::Result as AnyResult;
-use anyhow::bail;
 use anyhow::ensure;
 use ino_path::PathExt;
 use ino_tap::TapExt;
@@ -19,6 +18,7 @@ use rand::RngExt;
 use tracing::debug;
 use tracing::info;
 use tracing::trace;
+use tracing::warn;
 
 // TODO: move dst conflict check here?
 
@@ -123,6 +123,14 @@ impl StepQueue {
             steps.push_back(step);
         }
 
+        // Removes go first, so paths vacated by them are free before
+        // any create or replace tries to claim them, e.g. a symlink
+        // at "/a" being turned into a directory holding "/a/b".
+        // The sort is stable, the order within each kind is kept.
+        steps
+            .make_contiguous()
+            .sort_by_key(|it| !matches!(it, Step::Remove { .. }));
+
         ensure!(
             new_blueprint_symlinks
                 .iter()
@@ -135,6 +143,46 @@ impl StepQueue {
     }
 }
 
+impl StepQueue {
+    /// Check the feasibility of every step, collecting all the errors
+    /// instead of stopping at the first one.
+    ///
+    /// The checks are stat-bound, `parallel` spreads them across
+    /// threads for large blueprints. Either way the errors come out
+    /// in the order of steps. Execution is always serial.
+    ///
+    /// Since removes are executed first, dsts of removes are considered
+    /// vacated when checking the other steps.
+    #[tracing::instrument(skip(self))]
+    pub fn check_feasibility(
+        &self,
+        require_src: bool,
+        parallel: bool,
+    ) -> Vec<StepError> {
+        debug!("check feasibility of all steps");
+        let vacated = self
+            .steps
+            .iter()
+            .filter_map(|it| match it {
+                Step::Remove { old_symlink } => {
+                    Some(old_symlink.dst.as_ref())
+                }
+                _ => None,
+            })
+            .collect::<HashSet<&Path>>();
+        trace!(?vacated);
+        let check = |it: &Step| {
+            it.clone().check_feasibility(require_src, &vacated).err()
+        };
+        if parallel {
+            use rayon::prelude::*;
+            self.steps.par_iter().filter_map(check).collect()
+        } else {
+            self.steps.iter().filter_map(check).collect()
+        }
+    }
+}
+
 impl Iterator for StepQueue {
     type Item = Step;
     fn next(&mut self) -> Option<Self::Item> {
@@ -159,6 +207,83 @@ pub enum Step {
     Nothing,
 }
 
+impl std::fmt::Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Create { new_symlink } => write!(
+                f,
+                r#"create "{}" -> "{}""#,
+                new_symlink.dst.display(),
+                new_symlink.src.display(),
+            ),
+            Self::Remove { old_symlink } => {
+                write!(f, r#"remove "{}""#, old_symlink.dst.display())
+            }
+            Self::Replace {
+                new_symlink,
+                old_symlink,
+            } => write!(
+                f,
+                r#"replace "{}" -> "{}" (was "{}")"#,
+                new_symlink.dst.display(),
+                new_symlink.src.display(),
+                old_symlink.src.display(),
+            ),
+            Self::Nothing => write!(f, "nothing"),
+        }
+    }
+}
+
+/// Errors of checking or executing a [`Step`].
+#[derive(thiserror::Error, Debug)]
+pub enum StepError {
+    #[error(r#"Symlink target "{dst}" is not controlled by us, refuse to {action}"#)]
+    Collision { dst: PathBuf, action: &'static str },
+
+    #[error(r#"Symlink source "{src}" does not exist"#)]
+    SrcMissing { src: PathBuf },
+
+    #[error(
+        r#"Path component "{ancestor}" exists but is not a directory, cannot create symlink at "{dst}""#
+    )]
+    NotADirectory { ancestor: PathBuf, dst: PathBuf },
+
+    #[error(r#"No existing ancestor found for dst "{dst}""#)]
+    NoAncestor { dst: PathBuf },
+
+    #[error(r#"Failed to {what} "{path}""#)]
+    Io {
+        what: &'static str,
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    /// Both placing the temporary symlink and cleaning it up failed.
+    #[error(
+        r#"Failed to remove intermediate symlink "{tmp}" after failing to place it ({rename}), filesystem might be cooked"#
+    )]
+    Stranded {
+        tmp: PathBuf,
+        rename: std::io::Error,
+        source: std::io::Error,
+    },
+
+    #[error("[BUG] {0}")]
+    Bug(&'static str),
+}
+
+impl StepError {
+    /// Make a closure for [`Result::map_err`] turning an [`std::io::Error`]
+    /// into [`Self::Io`] about the given `path`.
+    fn io(
+        what: &'static str,
+        path: &Path,
+    ) -> impl FnOnce(std::io::Error) -> Self {
+        let path = path.to_path_buf();
+        move |source| Self::Io { what, path, source }
+    }
+}
+
 impl Step {
     /// Check whether this step is feasible without mutating the filesystem.
     ///
@@ -166,28 +291,67 @@ impl Step {
     /// does not probe writability — see [`Self::ensure_creatable_topology`]
     /// for the rationale. ENOSPC, permission errors, and similar surface
     /// only at [`Self::execute`] time.
+    ///
+    /// A missing src of the symlink to be created is an error if
+    /// `require_src` is set, otherwise only a warning.
+    ///
+    /// `vacated` are the paths which will have been removed by the
+    /// time this step is executed. If the dst lies under one of them,
+    /// whatever is currently there is irrelevant, e.g. the old
+    /// symlink at "/a" pointing to a directory containing "b" doesn't
+    /// collide with a new symlink at "/a/b".
     #[inline]
-    pub fn check_feasibility(self) -> AnyResult<()> {
-        self.real_execute(true)
+    pub fn check_feasibility(
+        self,
+        require_src: bool,
+        vacated: &HashSet<&Path>,
+    ) -> Result<(), StepError> {
+        let new_symlink = match &self {
+            Self::Create { new_symlink }
+            | Self::Replace { new_symlink, .. } => new_symlink,
+            Self::Remove { .. } | Self::Nothing => {
+                return self.real_execute(true, require_src);
+            }
+        };
+        let Some(vacated) = new_symlink
+            .dst
+            .ancestors()
+            .skip(1)
+            .find(|it| vacated.contains(it))
+        else {
+            return self.real_execute(true, require_src);
+        };
+        debug!(?vacated, "dst is under a path to be removed");
+        Self::ensure_src_exists(&new_symlink.src, require_src)?;
+        Self::ensure_creatable_topology(vacated)
     }
 
     #[inline]
-    pub fn execute(self) -> AnyResult<()> {
-        self.real_execute(false)
+    pub fn execute(self) -> Result<(), StepError> {
+        self.real_execute(false, false)
     }
 
     #[tracing::instrument(name = "step_execute", skip(self))]
-    fn real_execute(self, dry: bool) -> AnyResult<()> {
+    fn real_execute(
+        self,
+        dry: bool,
+        require_src: bool,
+    ) -> Result<(), StepError> {
         trace!(?self);
         match self {
             Self::Create { new_symlink } => {
-                Self::create_symlink(new_symlink, dry)?;
+                Self::create_symlink(new_symlink, dry, require_src)?;
             }
 
             Self::Replace {
                 new_symlink,
                 old_symlink,
-            } => Self::replace_symlink(new_symlink, old_symlink, dry)?,
+            } => Self::replace_symlink(
+                new_symlink,
+                old_symlink,
+                dry,
+                require_src,
+            )?,
 
             Self::Remove { old_symlink } => {
                 Self::remove_symlink(old_symlink, dry)?;
@@ -203,31 +367,26 @@ impl Step {
 
     #[tracing::instrument]
     #[inline]
-    fn create_symlink(new_symlink: Symlink, dry: bool) -> AnyResult<()> {
+    fn create_symlink(
+        new_symlink: Symlink,
+        dry: bool,
+        require_src: bool,
+    ) -> Result<(), StepError> {
         let Symlink { src, dst } = new_symlink;
         let dst_fact = DstFact::check(&src, &dst)?;
 
         if dst_fact.is_collision() {
             debug!("dst collides");
-            bail!(
-                r#"Symlink target "{}" is occupied by another file"#,
-                dst.display()
-            );
-        }
-
-        // N.B. We deliberately allow src to not exist — creating links to
-        // not-yet-existing targets is a legitimate use case (e.g. linking
-        // before installing). Emit a trace so typos are diagnosable.
-        if !src.try_exists().unwrap_or(false) {
-            trace!(
-                ?src,
-                "src does not exist; will create a dangling symlink"
-            );
+            return Err(StepError::Collision {
+                dst: dst.to_path_buf(),
+                action: "create",
+            });
         }
 
         // N.B. early return
         if dry {
             debug!("dry run, check feasibility");
+            Self::ensure_src_exists(&src, require_src)?;
             Self::ensure_creatable_topology(&dst)?;
             return Ok(());
         }
@@ -244,11 +403,7 @@ impl Step {
         }
 
         debug!("ready to create the real symlink");
-        symlink(&src, &dst).with_context(|| {
-            format!(r#"Failed to create symlink "{}""#, dst.display())
-        })?;
-
-        Ok(())
+        Self::symlink_atomically(&src, &dst, false)
     }
 
     #[tracing::instrument]
@@ -257,7 +412,8 @@ impl Step {
         new_symlink: Symlink,
         old_symlink: Symlink,
         dry: bool,
-    ) -> AnyResult<()> {
+        require_src: bool,
+    ) -> Result<(), StepError> {
         let Symlink {
             src: new_src,
             dst: new_dst,
@@ -267,7 +423,9 @@ impl Step {
             dst: old_dst,
         } = old_symlink;
 
-        ensure!(new_dst == old_dst, "[BUG] new_dst not equals to old_dst");
+        if new_dst != old_dst {
+            return Err(StepError::Bug("new_dst not equals to old_dst"));
+        }
 
         let dst = new_dst;
         drop(old_dst);
@@ -275,11 +433,10 @@ impl Step {
 
         if dst_fact.is_collision() {
             debug!("dst collides");
-            bail!(
-                r#"Symlink target "{}" is not controlled by us, \
-                refuse to replace"#,
-                dst.display(),
-            );
+            return Err(StepError::Collision {
+                dst: dst.to_path_buf(),
+                action: "replace",
+            });
         }
 
         // If dst does not exist, replace essentially becomes create
@@ -291,6 +448,7 @@ impl Step {
         // N.B. early rerun
         if dry {
             debug!("dry run, check feasibility");
+            Self::ensure_src_exists(&new_src, require_src)?;
             Self::ensure_creatable_topology(&dst)?;
             return Ok(());
         }
@@ -310,60 +468,23 @@ impl Step {
             Self::create_parent_dirs(parent)?;
         }
 
-        // attempt to atomic replace
-        let tmp_dst = {
-            use rand::distr::Alphanumeric;
-            trace!("generate temporary dst");
-            let suffix = rand::rng()
-                .sample_iter(&Alphanumeric)
-                .take(6)
-                .map(char::from)
-                .collect::<String>();
-            let mut ostr = dst.as_os_str().to_owned();
-            ostr.push(suffix);
-            let tmp_dst = PathBuf::from(ostr);
-            trace!(?tmp_dst);
-            tmp_dst
-        };
-        symlink(new_src, &tmp_dst).with_context(|| {
-            format!(
-                r#"Failed to link to the temporary target "{}", \
-                    the existing symlink is intact"#,
-                tmp_dst.display(),
-            )
-        })?;
-        // posix says it's atomic
-        let rename_ret = rename(&tmp_dst, &dst).with_context(|| {
-            format!(r#"Failed to replace symlink "{}""#, dst.display())
-        });
-        if let Err(rename_err) = rename_ret {
-            debug!("error when renaming symlink, remove tmp file");
-            // If cleanup fails too, surface both — the original rename
-            // error is what the user actually needs to diagnose.
-            if let Err(cleanup_err) = remove_file(&tmp_dst) {
-                return Err(cleanup_err).context(format!(
-                    r#"Failed to remove intermediate symlink \
-                        "{}", filesystem might be cooked. \
-                        Original rename error: {rename_err}"#,
-                    tmp_dst.display(),
-                ));
-            }
-            return Err(rename_err);
-        }
-        Ok(())
+        debug!("ready to replace the symlink");
+        Self::symlink_atomically(&new_src, &dst, true)
     }
 
-    fn remove_symlink(old_symlink: Symlink, dry: bool) -> AnyResult<()> {
+    fn remove_symlink(
+        old_symlink: Symlink,
+        dry: bool,
+    ) -> Result<(), StepError> {
         let Symlink { src, dst } = old_symlink;
         let dst_fact = DstFact::check(&src, &dst)?;
 
         if dst_fact.is_collision() {
             debug!("dst collides");
-            bail!(
-                r#"Symlink target "{}" is not controlled by us, \
-                refuse to remove"#,
-                dst.display(),
-            );
+            return Err(StepError::Collision {
+                dst: dst.to_path_buf(),
+                action: "remove",
+            });
         }
 
         // N.B. early return
@@ -380,9 +501,8 @@ impl Step {
         }
 
         debug!("ready to remove the old symlink");
-        remove_file(&dst).with_context(|| {
-            format!(r#"Failed to remove symlink "{}""#, dst.display())
-        })?;
+        remove_file(&dst)
+            .map_err(StepError::io("remove symlink", &dst))?;
 
         if let Some(parent) = dst.parent() {
             Self::remove_empty_parent_dirs(parent)?;
@@ -391,16 +511,111 @@ impl Step {
         Ok(())
     }
 
+    /// Create the symlink `dst -> src` by linking a temporary sibling
+    /// of `dst` first then renaming it over `dst`. POSIX guarantees the
+    /// rename to be atomic, so `dst` is either untouched or points to
+    /// `src`, never missing or half-made.
+    ///
+    /// Unless `overwrite` is set, the rename refuses to clobber anything
+    /// that appeared at `dst` after it was checked, reporting
+    /// [`StepError::Collision`] instead.
     #[inline]
     #[tracing::instrument]
-    fn create_parent_dirs(path: &Path) -> AnyResult<()> {
-        debug!("attempt to create parent dirs");
-        std::fs::create_dir_all(path).with_context(|| {
-            format!(
-                r#"Failed to create parent directories of "{}""#,
-                path.display()
+    fn symlink_atomically(
+        src: &Path,
+        dst: &Path,
+        overwrite: bool,
+    ) -> Result<(), StepError> {
+        let tmp_dst = {
+            use rand::distr::Alphanumeric;
+            trace!("generate temporary dst");
+            let suffix = rand::rng()
+                .sample_iter(&Alphanumeric)
+                .take(6)
+                .map(char::from)
+                .collect::<String>();
+            let mut ostr = dst.as_os_str().to_owned();
+            ostr.push(suffix);
+            let tmp_dst = PathBuf::from(ostr);
+            trace!(?tmp_dst);
+            tmp_dst
+        };
+        symlink(src, &tmp_dst).map_err(StepError::io(
+            "link the temporary target",
+            &tmp_dst,
+        ))?;
+        // posix says it's atomic
+        let placed = if overwrite {
+            rename(&tmp_dst, dst)
+        } else {
+            use rustix::fs::CWD;
+            use rustix::fs::RenameFlags;
+            rustix::fs::renameat_with(
+                CWD,
+                &tmp_dst,
+                CWD,
+                dst,
+                RenameFlags::NOREPLACE,
             )
-        })?;
+            .map_err(std::io::Error::from)
+        };
+        if let Err(rename_err) = placed {
+            debug!("error when renaming symlink, remove tmp file");
+            // If cleanup fails too, surface both — the original rename
+            // error is what the user actually needs to diagnose.
+            if let Err(cleanup_err) = remove_file(&tmp_dst) {
+                return Err(StepError::Stranded {
+                    tmp: tmp_dst,
+                    rename: rename_err,
+                    source: cleanup_err,
+                });
+            }
+            if rename_err.kind() == std::io::ErrorKind::AlreadyExists {
+                debug!("dst appeared after the check");
+                return Err(StepError::Collision {
+                    dst: dst.to_path_buf(),
+                    action: "create",
+                });
+            }
+            return Err(StepError::io("place symlink", dst)(rename_err));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    #[tracing::instrument]
+    fn create_parent_dirs(path: &Path) -> Result<(), StepError> {
+        debug!("attempt to create parent dirs");
+        std::fs::create_dir_all(path)
+            .map_err(StepError::io("create parent directories", path))?;
+        Ok(())
+    }
+
+    /// Bail if `src` doesn't exist and `require_src` is set,
+    /// otherwise only warn about the dangling symlink to be created.
+    ///
+    /// N.B. Missing src is allowed by default — creating links to
+    /// not-yet-existing targets is a legitimate use case (e.g. linking
+    /// before installing).
+    #[inline]
+    #[tracing::instrument]
+    fn ensure_src_exists(
+        src: &Path,
+        require_src: bool,
+    ) -> Result<(), StepError> {
+        debug!("check existence of src");
+        if src
+            .try_exists_no_traverse()
+            .map_err(StepError::io("check existence of", src))?
+        {
+            return Ok(());
+        }
+        if require_src {
+            return Err(StepError::SrcMissing {
+                src: src.to_path_buf(),
+            });
+        }
+        warn!(?src, "src does not exist, will create a dangling symlink");
         Ok(())
     }
 
@@ -414,34 +629,32 @@ impl Step {
     /// path produce clear OS-level messages; topology errors do not.
     #[inline]
     #[tracing::instrument]
-    fn ensure_creatable_topology(dst: &Path) -> AnyResult<()> {
+    fn ensure_creatable_topology(dst: &Path) -> Result<(), StepError> {
         debug!("check topology of dst");
-        let Some(parent) = dst.parent() else {
-            bail!(r#"dst "{}" has no parent"#, dst.display());
+        let no_ancestor = || StepError::NoAncestor {
+            dst: dst.to_path_buf(),
         };
+        let parent = dst.parent().ok_or_else(no_ancestor)?;
         for ancestor in parent.ancestors() {
             match ancestor.symlink_metadata() {
                 Ok(md) if md.is_dir() || md.is_symlink() => return Ok(()),
-                Ok(_) => bail!(
-                    r#"Path component "{}" exists but is not a directory, \
-                        cannot create symlink at "{}""#,
-                    ancestor.display(),
-                    dst.display(),
-                ),
+                Ok(_) => {
+                    return Err(StepError::NotADirectory {
+                        ancestor: ancestor.to_path_buf(),
+                        dst: dst.to_path_buf(),
+                    });
+                }
                 Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
                     // Do nothing, skip
                 }
                 Err(err) => {
-                    return Err(err).with_context(|| {
-                        format!(
-                            r#"Failed to stat ancestor "{}""#,
-                            ancestor.display()
-                        )
-                    });
+                    return Err(StepError::io("stat ancestor", ancestor)(
+                        err,
+                    ));
                 }
             }
         }
-        bail!(r#"No existing ancestor found for dst "{}""#, dst.display());
+        Err(no_ancestor())
     }
 
     /// Walk up from `path` removing empty ancestor directories, stopping
@@ -452,21 +665,29 @@ impl Step {
     /// belong to lny.
     #[inline]
     #[tracing::instrument]
-    fn remove_empty_parent_dirs(path: &Path) -> AnyResult<()> {
+    fn remove_empty_parent_dirs(path: &Path) -> Result<(), StepError> {
         debug!("attempt to remove empty parent dirs");
         trace!(?path);
         for ances in path.ancestors() {
             trace!(?ances, "parent's ancestor");
-            let metadata = ances
-                .symlink_metadata()
-                .context("Failed to read ancestor metadata")?;
-            if metadata.is_dir() && ances.read_dir()?.next().is_none() {
+            let metadata = ances.symlink_metadata().map_err(
+                StepError::io("read metadata of ancestor", ances),
+            )?;
+            if metadata.is_dir()
+                && ances
+                    .read_dir()
+                    .map_err(StepError::io(
+                        "read ancestor directory",
+                        ances,
+                    ))?
+                    .next()
+                    .is_none()
+            {
                 debug!("ancestor dir is empty, remove it");
-                std::fs::remove_dir( ances )
-                    .with_context( || format!(
-                        r#"Failed to remove empty ancestor directory "{}""#,
-                        ances.display()
-                    ) )?;
+                std::fs::remove_dir(ances).map_err(StepError::io(
+                    "remove empty ancestor directory",
+                    ances,
+                ))?;
             } else {
                 debug!("not empty, skip remaining ancestors");
                 return Ok(());
@@ -493,14 +714,21 @@ pub enum DstFact {
 impl DstFact {
     #[inline]
     #[tracing::instrument(name = "dst_fact_check")]
-    pub fn check(src: &Path, dst: &Path) -> AnyResult<Self> {
+    pub fn check(src: &Path, dst: &Path) -> Result<Self, StepError> {
         debug!("check potential collision");
         // N.B. Don't use [`Path::exists`] because it follows symlink
-        if dst.try_exists_no_traverse()? {
+        if dst
+            .try_exists_no_traverse()
+            .map_err(StepError::io("check existence of", dst))?
+        {
             debug!("dst is occupied");
             if dst.is_symlink() {
                 debug!("dst is a symlink, do further checks");
-                if dst.read_link()? == src {
+                if dst
+                    .read_link()
+                    .map_err(StepError::io("read symlink", dst))?
+                    == src
+                {
                     debug!("dst symlink is ours");
                     Ok(Self::SymlinkToSrc)
                 } else {
@@ -690,7 +918,8 @@ mod test {
     }
 
     /// Regression for BUGS.md #4: the iterator must yield steps in
-    /// insertion order (FIFO), not LIFO.
+    /// insertion order (FIFO), not LIFO, except that removes are
+    /// moved ahead of everything else.
     #[test]
     fn step_queue_is_fifo() {
         // Push order: new-bp symlinks first (Create/Replace/Nothing),
@@ -707,12 +936,48 @@ mod test {
 
         let mut q = StepQueue::new(new_bp, old_bp).unwrap();
 
-        assert!(matches!(q.next(), Some(Step::Create { .. })));
-        assert!(matches!(q.next(), Some(Step::Create { .. })));
         assert!(matches!(q.next(), Some(Step::Remove { .. })));
+        assert!(matches!(
+            q.next(),
+            Some(Step::Create { new_symlink }) if new_symlink.src.as_os_str() == "/a"
+        ));
+        assert!(matches!(
+            q.next(),
+            Some(Step::Create { new_symlink }) if new_symlink.src.as_os_str() == "/b"
+        ));
         assert!(q.next().is_none());
     }
 
+    #[test]
+    fn parallel_check_feasibility() {
+        let top = make_tempdir!();
+        let src =
+            top.child(make_random_str!()).tap(|it| it.touch().unwrap());
+
+        let symlinks = (0..64)
+            .map(|idx| {
+                let dst = top.child(make_random_str!());
+                if idx % 3 == 0 {
+                    dst.touch().unwrap();
+                }
+                make_symlink!(src.to_str().unwrap(), dst.to_str().unwrap())
+            })
+            .collect_vec();
+        let new_bp =
+            Blueprint::empty().tap_mut(|it| it.symlinks = symlinks);
+        let q = StepQueue::new(new_bp, Blueprint::empty()).unwrap();
+
+        let [serial, parallel] = [false, true].map(|parallel| {
+            q.check_feasibility(false, parallel)
+                .into_iter()
+                .map(|it| it.to_string())
+                .collect_vec()
+        });
+
+        assert!(serial.len() == 22);
+        assert!(serial == parallel);
+    }
+
     #[test]
     fn check_collision() {
         let top = make_tempdir!();
@@ -798,6 +1063,134 @@ mod test {
         }
     }
 
+    #[test]
+    fn step_error_variants() {
+        let top = make_tempdir!();
+
+        // 1. collision
+        {
+            let src = top.child(make_random_str!());
+            let dst = top
+                .child(make_random_str!())
+                .tap(|it| it.touch().unwrap());
+            let sym = make_symlink!(
+                src.to_str().unwrap(),
+                dst.to_str().unwrap()
+            );
+            let err = Step::Create { new_symlink: sym }
+                .check_feasibility(false, &HashSet::new())
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                StepError::Collision { dst: it, action: "create" }
+                    if it == dst.path()
+            ));
+        }
+
+        // 2. missing src
+        {
+            let src = top.child(make_random_str!());
+            let dst = top.child(make_random_str!());
+            let sym = make_symlink!(
+                src.to_str().unwrap(),
+                dst.to_str().unwrap()
+            );
+            let err = Step::Create { new_symlink: sym }
+                .check_feasibility(true, &HashSet::new())
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                StepError::SrcMissing { src: it } if it == src.path()
+            ));
+        }
+
+        // 3. ancestor is not a directory
+        {
+            let src = top
+                .child(make_random_str!())
+                .tap(|it| it.touch().unwrap());
+            let file = top
+                .child(make_random_str!())
+                .tap(|it| it.touch().unwrap());
+            let dst = file.child(make_random_str!());
+            let sym = make_symlink!(
+                src.to_str().unwrap(),
+                dst.to_str().unwrap()
+            );
+            // N.B. stat "file/dst" fails with ENOTDIR before
+            // the topology check is reached
+            let err = Step::Create { new_symlink: sym }
+                .check_feasibility(false, &HashSet::new())
+                .unwrap_err();
+            assert!(
+                matches!(err, StepError::Io { path, .. } if path == dst.path())
+            );
+            let err = Step::ensure_creatable_topology(&dst).unwrap_err();
+            assert!(matches!(
+                err,
+                StepError::NotADirectory { ancestor, .. }
+                    if ancestor == file.path()
+            ));
+        }
+
+        // 4. bug
+        {
+            let err = Step::Replace {
+                new_symlink: make_symlink!("/a", "/b"),
+                old_symlink: make_symlink!("/a", "/c"),
+            }
+            .execute()
+            .unwrap_err();
+            assert!(matches!(err, StepError::Bug(_)));
+        }
+    }
+
+    #[test]
+    fn require_src() {
+        let top = make_tempdir!();
+        let dst = top.child(make_random_str!());
+
+        // 1. src exists
+        {
+            let src = top
+                .child(make_random_str!())
+                .tap(|it| it.touch().unwrap());
+            let sym = make_symlink!(
+                src.to_str().unwrap(),
+                dst.to_str().unwrap()
+            );
+            let step = Step::Create { new_symlink: sym };
+            assert!(
+                step.clone()
+                    .check_feasibility(true, &HashSet::new())
+                    .is_ok()
+            );
+            assert!(
+                step.check_feasibility(false, &HashSet::new()).is_ok()
+            );
+        }
+
+        // 2. src is missing
+        {
+            let src = top.child(make_random_str!());
+            let sym = make_symlink!(
+                src.to_str().unwrap(),
+                dst.to_str().unwrap()
+            );
+            let step = Step::Create { new_symlink: sym };
+            assert!(
+                step.clone()
+                    .check_feasibility(true, &HashSet::new())
+                    .unwrap_err()
+                    .to_string()
+                    .contains("does not exist")
+            );
+            assert!(
+                step.check_feasibility(false, &HashSet::new()).is_ok()
+            );
+        }
+    }
+
     #[test]
     fn create_symlink() {
         let top = make_tempdir!();
@@ -811,7 +1204,6 @@ mod test {
 
         // 1. create symlink normally
         assert!(step.clone().execute().is_ok());
-        // TODO structural error
         assert!(
             dst.path().is_symlink()
                 && dst.path().read_link().unwrap() == src.path()
@@ -849,6 +1241,48 @@ mod test {
         }
     }
 
+    #[test]
+    fn symlink_atomically() {
+        let top = make_tempdir!();
+        let dir = top
+            .child(make_random_str!())
+            .tap(|it| it.create_dir_all().unwrap());
+        let src_a =
+            top.child(make_random_str!()).tap(|it| it.touch().unwrap());
+        let src_b =
+            top.child(make_random_str!()).tap(|it| it.touch().unwrap());
+        let dst = dir.child(make_random_str!());
+
+        // 1. fresh dst
+        assert!(Step::symlink_atomically(&src_a, &dst, false).is_ok());
+        assert!(dst.read_link().unwrap() == src_a.path());
+
+        // 2. an existing symlink gets overwritten in place
+        assert!(Step::symlink_atomically(&src_b, &dst, true).is_ok());
+        assert!(dst.read_link().unwrap() == src_b.path());
+
+        // 3. no temporary symlink is left behind
+        assert!(dir.read_dir().unwrap().count() == 1);
+
+        // 4. a file appearing at dst after the check is not
+        // overwritten when creating
+        {
+            let text = make_random_str!();
+            let dst = dir
+                .child(make_random_str!())
+                .tap(|it| it.write_str(&text).unwrap());
+            let err =
+                Step::symlink_atomically(&src_a, &dst, false).unwrap_err();
+            assert!(matches!(
+                err,
+                StepError::Collision { dst: ref d, action: "create" }
+                    if d == dst.path()
+            ));
+            assert!(std::fs::read_to_string(&dst).unwrap() == text);
+            assert!(dir.read_dir().unwrap().count() == 2);
+        }
+    }
+
     #[test]
     fn remove_symlink() {
         let top = make_tempdir!();
@@ -224,3 +224,130 @@ fn abs_path() {
             .contains("Path must be absolute")
     );
 }
+
+#[test]
+fn dry_run() {
+    let mut app = make_app!();
+    let top = make_tempdir!();
+
+    let src = top.child(make_random_str!()).tap(|it| it.touch().unwrap());
+    let dst = top.child(make_random_str!()).child(make_random_str!());
+
+    let new_bp = {
+        let j = serde_json::json! { {
+            "version": VERSION,
+            "symlinks": [
+                { "src": src.path(), "dst": dst.path() },
+            ]
+        } };
+        top.child("new_blueprint.json")
+            .tap(|it| it.write_str(&j.to_string()).unwrap())
+    };
+
+    let res = app
+        .arg("--new-blueprint")
+        .arg(new_bp.path())
+        .arg("--dry-run")
+        .output()
+        .unwrap();
+
+    assert!(res.status.success());
+    assert!(
+        String::from_utf8_lossy(&res.stdout)
+            .contains(dst.to_str().unwrap())
+    );
+    assert!(!dst.try_exists_no_traverse().unwrap());
+    assert!(!dst.parent().unwrap().try_exists_no_traverse().unwrap());
+}
+
+#[test]
+fn report_all_collisions() {
+    let mut app = make_app!();
+    let top = make_tempdir!();
+
+    let src = top.child(make_random_str!()).tap(|it| it.touch().unwrap());
+    let dst_a =
+        top.child(make_random_str!()).tap(|it| it.touch().unwrap());
+    let dst_b =
+        top.child(make_random_str!()).tap(|it| it.touch().unwrap());
+
+    let new_bp = {
+        let j = serde_json::json! { {
+            "version": VERSION,
+            "symlinks": [
+                { "src": src.path(), "dst": dst_a.path() },
+                { "src": src.path(), "dst": dst_b.path() },
+            ]
+        } };
+        top.child("new_blueprint.json")
+            .tap(|it| it.write_str(&j.to_string()).unwrap())
+    };
+
+    let res = app
+        .arg("--new-blueprint")
+        .arg(new_bp.path())
+        .output()
+        .unwrap();
+
+    assert!(!res.status.success());
+    let stderr = String::from_utf8_lossy(&res.stderr);
+    assert!(stderr.contains(dst_a.to_str().unwrap()));
+    assert!(stderr.contains(dst_b.to_str().unwrap()));
+    assert!(!dst_a.is_symlink() && !dst_b.is_symlink());
+}
+
+#[test]
+fn remove_before_create() {
+    use std::os::unix::fs::symlink;
+
+    let mut app = make_app!();
+    let top = make_tempdir!();
+
+    let src = top.child(make_random_str!()).tap(|it| it.touch().unwrap());
+    let src_dir = top
+        .child(make_random_str!())
+        .tap(|it| it.create_dir_all().unwrap());
+
+    // The old generation has a symlink to a directory at "dir", the new
+    // one wants "dir" to be a real directory holding another symlink.
+    // Creating first would put the new symlink into `src_dir`, and
+    // `src_dir` usually has a file with the same name already.
+    let old_dst = top.child(make_random_str!());
+    symlink(&src_dir, &old_dst).unwrap();
+    let name = make_random_str!();
+    let new_dst = old_dst.child(&name);
+    let shadowed = src_dir.child(&name).tap(|it| it.touch().unwrap());
+
+    let old_bp = {
+        let j = serde_json::json! { {
+            "version": VERSION,
+            "symlinks": [ { "src": src_dir.path(), "dst": old_dst.path() } ]
+        } };
+        top.child("old_blueprint.json")
+            .tap(|it| it.write_str(&j.to_string()).unwrap())
+    };
+
+    let new_bp = {
+        let j = serde_json::json! { {
+            "version": VERSION,
+            "symlinks": [ { "src": src.path(), "dst": new_dst.path() } ]
+        } };
+        top.child("new_blueprint.json")
+            .tap(|it| it.write_str(&j.to_string()).unwrap())
+    };
+
+    let res = app
+        .arg("--new-blueprint")
+        .arg(new_bp.path())
+        .arg("--old-blueprint")
+        .arg(old_bp.path())
+        .output()
+        .unwrap();
+
+    assert!(res.status.success());
+    assert!(old_dst.symlink_metadata().unwrap().is_dir());
+    assert!(shadowed.symlink_metadata().unwrap().is_file());
+    assert!(
+        new_dst.is_symlink() && new_dst.read_link().unwrap() == src.path()
+    );
+}